@@ -1,8 +1,12 @@
 use std::ptr;
 
+mod queue;
+use queue::Queue;
+
 pub struct List<T> {
     head: Link<T>,
     tail: *mut Node<T>,
+    len: usize,
 }
 
 type Link<T> = *mut Node<T>;
@@ -12,11 +16,20 @@ struct Node<T> {
     next: Link<T>,
 }
 
+pub struct IntoIter<T>(List<T>);
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
 impl<T> List<T> {
     pub fn new() -> Self {
         List {
             head: ptr::null_mut(),
             tail: ptr::null_mut(),
+            len: 0,
         }
     }
 
@@ -33,6 +46,7 @@ impl<T> List<T> {
                 self.head = raw_tail;
             }
             self.tail = raw_tail;
+            self.len += 1;
         }
     }
 
@@ -46,10 +60,111 @@ impl<T> List<T> {
                 if self.head.is_null() {
                     self.tail = ptr::null_mut();
                 }
+                self.len -= 1;
                 Some(head.elem)
             }
         }
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_null()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // 由于节点只持有next指针, 从尾部弹出需要从头遍历找到倒数第二个节点
+    fn pop_back(&mut self) -> Option<T> {
+        unsafe {
+            if self.tail.is_null() {
+                return None;
+            }
+            if self.head == self.tail {
+                return self.pop();
+            }
+            let mut cur = self.head;
+            while (*cur).next != self.tail {
+                cur = (*cur).next;
+            }
+            let old_tail = Box::from_raw(self.tail);
+            (*cur).next = ptr::null_mut();
+            self.tail = cur;
+            self.len -= 1;
+            Some(old_tail.elem)
+        }
+    }
+
+    pub fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        unsafe {
+            Iter {
+                next: self.head.as_ref(),
+            }
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        unsafe {
+            IterMut {
+                next: self.head.as_mut(),
+            }
+        }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| unsafe {
+            self.next = node.next.as_ref();
+            &node.elem
+        })
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| unsafe {
+            self.next = node.next.as_mut();
+            &mut node.elem
+        })
+    }
+}
+
+impl<T> Queue<T> for List<T> {
+    fn push(&mut self, item: T) {
+        List::push(self, item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        List::pop(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        List::is_empty(self)
+    }
+
+    fn len(&self) -> usize {
+        List::len(self)
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -99,4 +214,81 @@ mod test {
         assert_eq!(list.pop(), Some(7));
         assert_eq!(list.pop(), None);
     }
+
+    #[test]
+    fn queue_trait() {
+        use crate::queue::Queue;
+
+        let mut list = List::new();
+        assert!(Queue::is_empty(&list));
+        assert_eq!(Queue::len(&list), 0);
+
+        Queue::push(&mut list, 1);
+        Queue::push(&mut list, 2);
+        assert!(!Queue::is_empty(&list));
+        assert_eq!(Queue::len(&list), 2);
+
+        assert_eq!(Queue::pop(&mut list), Some(1));
+        assert_eq!(Queue::pop(&mut list), Some(2));
+        assert_eq!(Queue::pop(&mut list), None);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn into_iter_double_ended() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        list.push(4);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next(), None);
+    }
 }
@@ -0,0 +1,7 @@
+// FIFO 语义的通用接口
+pub trait Queue<T> {
+    fn push(&mut self, item: T);
+    fn pop(&mut self) -> Option<T>;
+    fn is_empty(&self) -> bool;
+    fn len(&self) -> usize;
+}
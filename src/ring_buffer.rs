@@ -0,0 +1,132 @@
+mod queue;
+use queue::Queue;
+
+// 数组实现的定长环形队列, 相比链表版本省去了逐元素的堆分配
+// 多开辟一个槽位用于区分满和空的状态 (head == tail 代表空)
+pub struct FixSizeQueue<T> {
+    buf: Box<[Option<T>]>,
+    head: usize,
+    tail: usize,
+}
+
+impl<T> FixSizeQueue<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut buf = Vec::with_capacity(capacity + 1);
+        buf.resize_with(capacity + 1, || None);
+        FixSizeQueue {
+            buf: buf.into_boxed_slice(),
+            head: 0,
+            tail: 0,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        (self.tail + 1) % self.buf.len() == self.head
+    }
+
+    // 入队, 队列已满时返回Err并归还元素
+    pub fn push(&mut self, elem: T) -> Result<(), T> {
+        let next = (self.tail + 1) % self.buf.len();
+        if next == self.head {
+            return Err(elem);
+        }
+        self.buf[self.tail] = Some(elem);
+        self.tail = next;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.head == self.tail {
+            return None;
+        }
+        let elem = self.buf[self.head].take();
+        self.head = (self.head + 1) % self.buf.len();
+        elem
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head == self.tail
+    }
+
+    pub fn len(&self) -> usize {
+        let capacity = self.buf.len();
+        (self.tail + capacity - self.head) % capacity
+    }
+}
+
+impl<T> Queue<T> for FixSizeQueue<T> {
+    fn push(&mut self, item: T) {
+        // trait要求push返回();队列已满时静默丢弃, 调用方可改用FixSizeQueue::push获取Result
+        let _ = FixSizeQueue::push(self, item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        FixSizeQueue::pop(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        FixSizeQueue::is_empty(self)
+    }
+
+    fn len(&self) -> usize {
+        FixSizeQueue::len(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FixSizeQueue;
+    use crate::queue::Queue;
+
+    #[test]
+    fn basics() {
+        let mut queue = FixSizeQueue::with_capacity(3);
+        assert_eq!(queue.pop(), None);
+
+        assert!(queue.push(1).is_ok());
+        assert!(queue.push(2).is_ok());
+        assert!(queue.push(3).is_ok());
+        assert!(queue.is_full());
+        assert_eq!(queue.push(4), Err(4));
+
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop(), Some(2));
+
+        assert!(queue.push(5).is_ok());
+        assert!(queue.push(6).is_ok());
+        assert!(queue.is_full());
+
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), Some(5));
+        assert_eq!(queue.pop(), Some(6));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn len() {
+        let mut queue = FixSizeQueue::with_capacity(4);
+        assert_eq!(queue.len(), 0);
+
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        assert_eq!(queue.len(), 2);
+
+        queue.pop();
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn queue_trait() {
+        let mut queue = FixSizeQueue::with_capacity(2);
+        assert!(Queue::is_empty(&queue));
+
+        Queue::push(&mut queue, 1);
+        Queue::push(&mut queue, 2);
+        assert_eq!(Queue::len(&queue), 2);
+
+        assert_eq!(Queue::pop(&mut queue), Some(1));
+        assert_eq!(Queue::pop(&mut queue), Some(2));
+        assert_eq!(Queue::pop(&mut queue), None);
+    }
+}
@@ -1,3 +1,6 @@
+mod stack;
+use stack::Stack;
+
 struct Node<T> {
     elem: T,
     next: Link<T>,
@@ -74,6 +77,63 @@ impl<T> List<T> {
         // 避免self.head的所有权被转移
         self.head.as_mut().map(|node| &mut node.elem)
     }
+
+    // 按下标访问元素, 从head开始走at步
+    pub fn get(&self, at: usize) -> Option<&T> {
+        let mut cur = self.head.as_deref();
+        for _ in 0..at {
+            cur = cur?.next.as_deref();
+        }
+        cur.map(|node| &node.elem)
+    }
+
+    // 在下标at处插入新元素, at超出链表长度时退化为在尾部追加
+    pub fn insert_at(&mut self, at: usize, elem: T) {
+        let mut cur = &mut self.head;
+        let mut remaining = at;
+        while remaining > 0 && cur.is_some() {
+            cur = &mut cur.as_mut().unwrap().next;
+            remaining -= 1;
+        }
+        let new_node = Box::new(Node {
+            elem,
+            next: cur.take(),
+        });
+        *cur = Some(new_node);
+    }
+
+    // 移除下标at处的元素并返回, at越界时返回None
+    pub fn remove_at(&mut self, at: usize) -> Option<T> {
+        let mut cur = &mut self.head;
+        for _ in 0..at {
+            cur = &mut cur.as_mut()?.next;
+        }
+        cur.take().map(|node| {
+            *cur = node.next;
+            node.elem
+        })
+    }
+
+    // 在下标at处将链表一分为二, self保留前半部分, 返回由后半部分组成的新链表
+    // 链接本身只是指针的移动, 不涉及元素拷贝
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        let mut cur = &mut self.head;
+        let mut remaining = at;
+        while remaining > 0 && cur.is_some() {
+            cur = &mut cur.as_mut().unwrap().next;
+            remaining -= 1;
+        }
+        List { head: cur.take() }
+    }
+
+    // 将other链表整体接到self的尾部, other变为空链表
+    pub fn append(&mut self, other: &mut List<T>) {
+        let mut cur = &mut self.head;
+        while let Some(node) = cur {
+            cur = &mut node.next;
+        }
+        *cur = other.head.take();
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -124,9 +184,28 @@ impl<'a, T> Iterator for IterMut<'a, T> {
     }
 }
 
+impl<T> Stack<T> for List<T> {
+    fn push(&mut self, item: T) {
+        List::push(self, item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        List::pop(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    fn len(&self) -> usize {
+        self.iter().count()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::List;
+    use crate::stack::Stack;
 
     #[test]
     fn basics() {
@@ -233,4 +312,103 @@ mod test {
         assert_eq!(iter.next(), Some(&mut 1));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn get() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&1));
+        assert_eq!(list.get(3), None);
+    }
+
+    #[test]
+    fn insert_at() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        list.insert_at(1, 42);
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(1), Some(&42));
+        assert_eq!(list.get(2), Some(&2));
+        assert_eq!(list.get(3), Some(&1));
+
+        // 越界插入退化为尾部追加
+        list.insert_at(100, 99);
+        assert_eq!(list.get(4), Some(&99));
+        assert_eq!(list.get(5), None);
+    }
+
+    #[test]
+    fn remove_at() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        assert_eq!(list.remove_at(1), Some(2));
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(1), Some(&1));
+        assert_eq!(list.remove_at(5), None);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        list.push(4);
+
+        let mut tail = list.split_off(2);
+        assert_eq!(list.get(0), Some(&4));
+        assert_eq!(list.get(1), Some(&3));
+        assert_eq!(list.get(2), None);
+
+        assert_eq!(tail.pop(), Some(2));
+        assert_eq!(tail.pop(), Some(1));
+        assert_eq!(tail.pop(), None);
+    }
+
+    #[test]
+    fn append() {
+        let mut list = List::new();
+        list.push(2);
+        list.push(1);
+
+        let mut other = List::new();
+        other.push(4);
+        other.push(3);
+
+        list.append(&mut other);
+        assert_eq!(other.pop(), None);
+
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn stack_trait() {
+        let mut list = List::new();
+        assert!(Stack::is_empty(&list));
+        assert_eq!(Stack::len(&list), 0);
+
+        Stack::push(&mut list, 1);
+        Stack::push(&mut list, 2);
+        assert!(!Stack::is_empty(&list));
+        assert_eq!(Stack::len(&list), 2);
+
+        assert_eq!(Stack::pop(&mut list), Some(2));
+        assert_eq!(Stack::pop(&mut list), Some(1));
+        assert_eq!(Stack::pop(&mut list), None);
+    }
 }